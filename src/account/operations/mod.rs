@@ -0,0 +1,7 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#[cfg(feature = "participation")]
+pub mod participation;
+pub mod transaction;
+pub mod transfer;
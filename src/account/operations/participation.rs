@@ -0,0 +1,107 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_client::{
+    bee_block::output::Feature,
+    participation::types::{EventStatus, Participation, ParticipationEventId, ParticipationEventWithNodes},
+};
+
+use crate::account::{
+    handle::AccountHandle,
+    types::participation::{ParticipationEventOverview, ParticipationOverview},
+};
+
+impl AccountHandle {
+    /// Computes the account's [`ParticipationOverview`] by scanning every unspent output for a tagged
+    /// [`Participation`] payload and accruing voting power (amount held × milestones held so far, against
+    /// the node's latest milestone index) per event and answer.
+    pub async fn get_participation_overview(&self) -> crate::Result<ParticipationOverview> {
+        let account = self.read().await;
+        let latest_milestone_index = self.client().get_info().await?.node_info.status.latest_milestone.index;
+        let mut overview = ParticipationOverview::default();
+
+        for output_data in account.unspent_outputs().values() {
+            let Some(features) = output_data.output.features() else {
+                continue;
+            };
+
+            for feature in features.iter() {
+                let Feature::Tag(tag_feature) = feature else {
+                    continue;
+                };
+                let Ok(participation) = Participation::from_bytes(tag_feature.tag()) else {
+                    continue;
+                };
+
+                let power = accrued_power(
+                    output_data.output.amount(),
+                    output_data.metadata.milestone_index_booked,
+                    latest_milestone_index,
+                );
+
+                let entry = overview
+                    .participations
+                    .entry(participation.event_id)
+                    .or_insert_with(ParticipationEventOverview::default);
+                entry.power += power;
+                for answer in participation.answers {
+                    *entry
+                        .answers
+                        .entry(answer.question)
+                        .or_default()
+                        .entry(answer.answer)
+                        .or_default() += power;
+                }
+            }
+        }
+
+        Ok(overview)
+    }
+
+    /// Fetches every participation event the account's connected node knows about.
+    pub async fn get_participation_events(&self) -> crate::Result<Vec<ParticipationEventWithNodes>> {
+        Ok(self.client().events(None, None).await?)
+    }
+
+    /// Fetches the current status of a single participation event - the phase (upcoming/commencing/
+    /// holding/ended) the node computes from its latest milestone index against the event's start/end
+    /// milestones.
+    pub async fn get_participation_event_status(&self, event_id: &ParticipationEventId) -> crate::Result<EventStatus> {
+        Ok(self.client().event_status(event_id, None).await?)
+    }
+}
+
+/// Computes the voting power a held amount accrues: the amount multiplied by however many milestones have
+/// passed since it was booked, against the node's latest milestone index. Done in `u128` - `amount` can be
+/// close to the total token supply (~2.78e15) and a long-lived output can accrue millions of milestones
+/// held, which overflows `u64` well within a realistic holding period.
+fn accrued_power(amount: u64, milestone_index_booked: u32, latest_milestone_index: u32) -> u128 {
+    let milestones_held = latest_milestone_index.saturating_sub(milestone_index_booked);
+    u128::from(amount) * u128::from(milestones_held)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::accrued_power;
+
+    #[test]
+    fn accrued_power_does_not_overflow_u64() {
+        let amount = 2_779_530_283_277_761u64; // total circulating supply
+        let milestones_held = 10_000_000u32; // several years at a ~10s milestone cadence
+
+        let power = accrued_power(amount, 0, milestones_held);
+
+        assert_eq!(power, u128::from(amount) * u128::from(milestones_held));
+        assert!(power > u128::from(u64::MAX));
+    }
+
+    #[test]
+    fn accrued_power_is_zero_when_booked_after_latest_milestone() {
+        assert_eq!(accrued_power(1_000, 100, 50), 0);
+    }
+
+    #[test]
+    fn accrued_power_scales_with_milestones_held() {
+        assert_eq!(accrued_power(1_000, 10, 15), 5_000);
+    }
+}
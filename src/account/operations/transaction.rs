@@ -0,0 +1,28 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    account::{handle::AccountHandle, operations::transfer::account_address_map},
+    message_interface::dtos::{classify_transaction_recipients, TransactionDto},
+};
+
+impl AccountHandle {
+    /// Returns every transaction recorded by this account, each resolved to a [`TransactionDto`] with its
+    /// outputs classified against the account's own addresses so `ListTransactions`/`ListPendingTransactions`
+    /// consumers see real recipients instead of raw outputs.
+    pub async fn list_transactions(&self) -> crate::Result<Vec<TransactionDto>> {
+        let account = self.read().await;
+        let account_addresses = account_address_map(&account);
+
+        Ok(account
+            .transactions()
+            .values()
+            .map(|transaction| TransactionDto {
+                payload: transaction.payload.clone(),
+                recipients: classify_transaction_recipients(&transaction.payload, &account_addresses),
+                timestamp: transaction.timestamp,
+                inclusion_state: transaction.inclusion_state,
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,54 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use iota_client::bee_block::{address::Address, payload::transaction::TransactionPayload};
+use serde::Serialize;
+
+use crate::{
+    account::{handle::AccountHandle, types::Account},
+    message_interface::dtos::{classify_transaction_recipients, SentOutput},
+};
+
+/// The result of submitting a transaction, with its outputs already classified as external or
+/// wallet-internal recipients.
+#[derive(Debug, Clone, Serialize)]
+pub struct TransferResult {
+    /// Transaction id of the submitted transaction.
+    pub transaction_id: String,
+    /// Block id the transaction was submitted in, once known.
+    pub block_id: Option<String>,
+    /// Each output of the submitted transaction that has an address unlock condition, classified as an
+    /// external or wallet-internal recipient.
+    pub recipients: Vec<SentOutput>,
+}
+
+impl AccountHandle {
+    /// Builds the [`TransferResult`] for a transaction this account just submitted, classifying each of
+    /// its outputs against the account's own addresses so `SentTransfer`/`SentTransfers` consumers see real
+    /// recipients instead of raw outputs.
+    pub async fn build_transfer_result(
+        &self,
+        transaction_id: String,
+        block_id: Option<String>,
+        payload: &TransactionPayload,
+    ) -> TransferResult {
+        let account = self.read().await;
+        TransferResult {
+            transaction_id,
+            block_id,
+            recipients: classify_transaction_recipients(payload, &account_address_map(&account)),
+        }
+    }
+}
+
+/// Builds the `address -> is change address` map [`classify_transaction_recipients`] needs, from the
+/// account's own generated addresses.
+pub(crate) fn account_address_map(account: &Account) -> HashMap<Address, bool> {
+    account
+        .addresses()
+        .iter()
+        .map(|account_address| (*account_address.address(), account_address.internal()))
+        .collect()
+}
@@ -0,0 +1,20 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::Serialize;
+
+pub mod address;
+#[cfg(feature = "participation")]
+pub mod participation;
+
+/// Whether a transaction has been confirmed by a milestone yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum InclusionState {
+    /// The transaction is confirmed.
+    Confirmed,
+    /// The transaction hasn't been confirmed yet.
+    Pending,
+    /// The transaction's inputs were conflicting and it was never confirmed.
+    Conflicting,
+}
@@ -0,0 +1,30 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use iota_client::participation::types::ParticipationEventId;
+use serde::Serialize;
+
+/// The account's current participation (voting) state for a single event, aggregated from every unspent
+/// output that carries a matching participation tag.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ParticipationEventOverview {
+    /// Voting power currently backing this event: the sum of `amount held × milestones held` over every
+    /// unspent output tagged with a participation payload for it. `u128` because a single long-lived
+    /// output can overflow `u64` well within a realistic holding period.
+    pub power: u128,
+    /// Power backing each answer, keyed by question index and then answer index.
+    pub answers: HashMap<u8, HashMap<u8, u128>>,
+}
+
+/// A wallet-wide snapshot of participation, aggregated across every unspent output that carries
+/// participation tagged data.
+///
+/// Returned by [`AccountHandle::get_participation_overview`](crate::account::handle::AccountHandle::
+/// get_participation_overview).
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ParticipationOverview {
+    /// Per-event breakdown of the account's current participation, keyed by the voted-on event.
+    pub participations: HashMap<ParticipationEventId, ParticipationEventOverview>,
+}
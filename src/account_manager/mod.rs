@@ -0,0 +1,146 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::HashSet, sync::Arc};
+
+use iota_client::secret::SecretManager;
+use tokio::sync::RwLock;
+
+#[cfg(feature = "ledger_nano")]
+use crate::message_interface::dtos::LedgerNanoStatusDto;
+use crate::{
+    account::{handle::AccountHandle, types::Account},
+    message_interface::dtos::AccountDto,
+    storage::StorageAdapter,
+    Error,
+};
+
+/// Storage key under which the index of every account the manager has ever persisted is stored, so a cold
+/// start can enumerate what exists without fetching each account's full serialized state.
+const ACCOUNT_INDEXES_STORAGE_KEY: &str = "account-indexes";
+
+/// Storage key an individual account's serialized state is persisted under.
+fn account_storage_key(index: u32) -> String {
+    format!("account-{index}")
+}
+
+/// Holds every account known to the wallet and the secret manager used to sign and query hardware wallets
+/// on their behalf.
+pub struct AccountManager {
+    pub(crate) accounts: Arc<RwLock<Vec<AccountHandle>>>,
+    pub(crate) secret_manager: Arc<RwLock<SecretManager>>,
+    pub(crate) storage: Arc<dyn StorageAdapter>,
+    /// Guards against re-reading every account from storage more than once; flips to `true` the first time
+    /// [`ensure_accounts_loaded`](Self::ensure_accounts_loaded) hydrates `accounts` from the storage backend.
+    accounts_loaded: Arc<RwLock<bool>>,
+}
+
+impl AccountManager {
+    /// Queries the ledger secret manager for the connected device's current status.
+    #[cfg(feature = "ledger_nano")]
+    pub async fn get_ledger_nano_status(&self) -> crate::Result<LedgerNanoStatusDto> {
+        match &*self.secret_manager.read().await {
+            SecretManager::LedgerNano(ledger_nano) => {
+                let status = ledger_nano.get_ledger_nano_status().await;
+                Ok(LedgerNanoStatusDto {
+                    connected: status.connected(),
+                    locked: status.locked(),
+                    blind_signing_enabled: status.blind_signing_enabled(),
+                    device: status.device().map(|device| device.to_string()),
+                    app_name: status.app().map(|app| app.name().to_string()),
+                    app_version: status.app().map(|app| app.version().to_string()),
+                    buffer_size: status.buffer_size(),
+                })
+            }
+            _ => Err(Error::Error(
+                "the account manager isn't configured with a Ledger Nano secret manager".to_string(),
+            )),
+        }
+    }
+
+    /// Returns the index of every account the manager is tracking, hydrating from storage first so a cold
+    /// start (nothing loaded into `accounts` yet) still reports what's persisted.
+    pub async fn get_account_indexes(&self) -> crate::Result<Vec<u32>> {
+        self.ensure_accounts_loaded().await?;
+
+        let accounts = self.accounts.read().await;
+        let mut indexes = Vec::with_capacity(accounts.len());
+        for account in accounts.iter() {
+            indexes.push(account.read().await.index());
+        }
+        Ok(indexes)
+    }
+
+    /// Returns the [`AccountHandle`] for the account at `index`, hydrating from storage first if needed.
+    pub async fn get_account(&self, index: u32) -> crate::Result<AccountHandle> {
+        self.ensure_accounts_loaded().await?;
+
+        let accounts = self.accounts.read().await;
+        for account in accounts.iter() {
+            if account.read().await.index() == index {
+                return Ok(account.clone());
+            }
+        }
+        Err(Error::Error(format!("no account with index {index}")))
+    }
+
+    /// Loads every account persisted in storage into memory, returning the full set. Skips re-reading
+    /// storage if a previous call already hydrated `accounts`.
+    pub async fn load_all_accounts(&self) -> crate::Result<Vec<AccountDto>> {
+        self.ensure_accounts_loaded().await?;
+
+        let accounts = self.accounts.read().await;
+        let mut dtos = Vec::with_capacity(accounts.len());
+        for account in accounts.iter() {
+            dtos.push(AccountDto::from(&*account.read().await));
+        }
+        Ok(dtos)
+    }
+
+    /// Hydrates `accounts` from the storage backend the first time it's called; later calls are a no-op
+    /// until [`accounts_loaded`](Self::accounts_loaded) is reset.
+    async fn ensure_accounts_loaded(&self) -> crate::Result<()> {
+        if *self.accounts_loaded.read().await {
+            return Ok(());
+        }
+
+        let mut accounts_loaded = self.accounts_loaded.write().await;
+        // Re-check under the write lock in case another task hydrated accounts while we were waiting for
+        // it.
+        if !*accounts_loaded {
+            self.hydrate_accounts_from_storage().await?;
+            *accounts_loaded = true;
+        }
+        Ok(())
+    }
+
+    /// Reads every account persisted by the configured storage backend and appends an [`AccountHandle`] for
+    /// each one not already held in `accounts`.
+    async fn hydrate_accounts_from_storage(&self) -> crate::Result<()> {
+        let mut accounts = self.accounts.write().await;
+        let mut loaded_indexes = HashSet::with_capacity(accounts.len());
+        for account in accounts.iter() {
+            loaded_indexes.insert(account.read().await.index());
+        }
+
+        let stored_indexes = match self.storage.get(ACCOUNT_INDEXES_STORAGE_KEY).await? {
+            Some(indexes) => serde_json::from_str::<Vec<u32>>(&indexes).map_err(|e| Error::Error(e.to_string()))?,
+            None => Vec::new(),
+        };
+
+        for index in stored_indexes {
+            if loaded_indexes.contains(&index) {
+                continue;
+            }
+
+            let Some(serialized_account) = self.storage.get(&account_storage_key(index)).await? else {
+                continue;
+            };
+            let account: Account =
+                serde_json::from_str(&serialized_account).map_err(|e| Error::Error(e.to_string()))?;
+            accounts.push(AccountHandle::new(account, self.secret_manager.clone()));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,167 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use iota_client::bee_block::{address::Address, output::Output, payload::transaction::TransactionPayload};
+use serde::Serialize;
+
+/// The current status of a connected Ledger Nano hardware wallet, as reported by the ledger secret
+/// manager's status query.
+#[derive(Debug, Serialize)]
+#[cfg(feature = "ledger_nano")]
+pub struct LedgerNanoStatusDto {
+    /// Whether a Ledger Nano device is connected.
+    pub connected: bool,
+    /// Whether the device is locked.
+    pub locked: bool,
+    /// Whether blind signing is enabled in the opened app's settings.
+    pub blind_signing_enabled: bool,
+    /// The device type, if it could be determined (e.g. `"nanoS"`, `"nanoX"`).
+    pub device: Option<String>,
+    /// Name of the app currently open on the device (e.g. `"IOTA"`, `"Shimmer"`), if one is open.
+    pub app_name: Option<String>,
+    /// Version of the app currently open on the device, if one is open.
+    pub app_version: Option<String>,
+    /// Size of the device's signing buffer, used to tell whether a transaction will fit before attempting
+    /// to sign it.
+    pub buffer_size: Option<usize>,
+}
+
+/// Classifies one output of a transaction as paying out to an address outside the account (a genuine
+/// payment), back to one of the account's own change addresses (leftover from the transaction itself), or
+/// to one of the account's own receive addresses on purpose (a deliberate self-transfer), so consumers can
+/// filter the non-payment cases out of balance and history displays.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", content = "address")]
+pub enum Recipient {
+    /// The output pays an address that isn't tracked by this account.
+    External(Address),
+    /// The output pays back one of this account's own change addresses.
+    InternalChange(Address),
+    /// The output pays one of this account's own receive addresses on purpose.
+    InternalTransfer(Address),
+}
+
+impl Recipient {
+    /// Classifies `address` against the sending account's own addresses, keyed by whether each one is a
+    /// change address (`true`) or a receive address (`false`), labeling it
+    /// [`InternalChange`](Recipient::InternalChange) or [`InternalTransfer`](Recipient::InternalTransfer)
+    /// accordingly if it's one of the account's own.
+    pub fn classify(address: Address, account_addresses: &HashMap<Address, bool>) -> Self {
+        match account_addresses.get(&address) {
+            Some(true) => Recipient::InternalChange(address),
+            Some(false) => Recipient::InternalTransfer(address),
+            None => Recipient::External(address),
+        }
+    }
+}
+
+/// Which kind of output a [`SentOutput`] is, so consumers can tell e.g. an NFT transfer from a basic value
+/// transaction without inspecting the underlying transaction payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OutputKind {
+    Basic,
+    Alias,
+    Foundry,
+    Nft,
+    Treasury,
+}
+
+impl From<&Output> for OutputKind {
+    fn from(output: &Output) -> Self {
+        match output {
+            Output::Basic(_) => OutputKind::Basic,
+            Output::Alias(_) => OutputKind::Alias,
+            Output::Foundry(_) => OutputKind::Foundry,
+            Output::Nft(_) => OutputKind::Nft,
+            Output::Treasury(_) => OutputKind::Treasury,
+        }
+    }
+}
+
+/// One output of a sent transaction, resolved to a JSON-friendly recipient along with the amount and output
+/// kind needed to compute net outgoing amounts without re-walking the raw transaction payload.
+#[derive(Debug, Clone, Serialize)]
+pub struct SentOutput {
+    /// How the output's address classifies against the sending account.
+    pub recipient: Recipient,
+    /// Amount of tokens carried by this output.
+    pub amount: u64,
+    /// Which kind of output this is.
+    pub output_kind: OutputKind,
+}
+
+/// A transaction as seen by an account, with its outputs resolved to JSON-friendly recipients.
+#[derive(Debug, Clone, Serialize)]
+pub struct TransactionDto {
+    /// The signed transaction payload.
+    pub payload: TransactionPayload,
+    /// Each output of [`payload`](Self::payload) that has an address unlock condition, classified as an
+    /// external or wallet-internal recipient.
+    pub recipients: Vec<SentOutput>,
+    /// Milestone timestamp when the transaction was included, if confirmed.
+    pub timestamp: u128,
+    /// Whether the transaction is still pending inclusion.
+    pub inclusion_state: crate::account::types::InclusionState,
+}
+
+/// Resolves the recipient of every output in `payload` against the account's own addresses. Outputs
+/// without an address unlock condition (e.g. alias, foundry and treasury outputs) are left out.
+pub fn classify_transaction_recipients(
+    payload: &TransactionPayload,
+    account_addresses: &HashMap<Address, bool>,
+) -> Vec<SentOutput> {
+    let iota_client::bee_block::payload::transaction::TransactionEssence::Regular(essence) = payload.essence();
+    essence
+        .outputs()
+        .iter()
+        .filter_map(|output| {
+            let unlock_condition = output.unlock_conditions()?.address()?;
+            Some(SentOutput {
+                recipient: Recipient::classify(*unlock_condition.address(), account_addresses),
+                amount: output.amount(),
+                output_kind: OutputKind::from(output),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use iota_client::bee_block::address::Ed25519Address;
+
+    use super::*;
+
+    fn address(byte: u8) -> Address {
+        Address::Ed25519(Ed25519Address::new([byte; 32]))
+    }
+
+    #[test]
+    fn classify_external_address() {
+        let account_addresses = HashMap::new();
+        assert!(matches!(
+            Recipient::classify(address(1), &account_addresses),
+            Recipient::External(_)
+        ));
+    }
+
+    #[test]
+    fn classify_internal_change_address() {
+        let account_addresses = HashMap::from([(address(2), true)]);
+        assert!(matches!(
+            Recipient::classify(address(2), &account_addresses),
+            Recipient::InternalChange(_)
+        ));
+    }
+
+    #[test]
+    fn classify_internal_transfer_address() {
+        let account_addresses = HashMap::from([(address(3), false)]);
+        assert!(matches!(
+            Recipient::classify(address(3), &account_addresses),
+            Recipient::InternalTransfer(_)
+        ));
+    }
+}
@@ -0,0 +1,50 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#[cfg(feature = "participation")]
+use iota_client::participation::types::ParticipationEventId;
+use serde::Deserialize;
+
+/// The messages accepted by the [`MessageHandler`](crate::message_interface::MessageHandler), dispatched to
+/// either an account-manager-level action or, via [`AccountMethod`](crate::message_interface::AccountMethod),
+/// an individual account.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "cmd", content = "payload")]
+pub enum Message {
+    /// Queries the connected Ledger Nano device for its current status.
+    /// Expected response: [`LedgerNanoStatus`](crate::message_interface::Response::LedgerNanoStatus)
+    #[cfg(feature = "ledger_nano")]
+    GetLedgerNanoStatus,
+    /// Returns the index of every account tracked by the manager, first loading any accounts that are
+    /// only present in storage.
+    /// Expected response: [`AccountIndexes`](crate::message_interface::Response::AccountIndexes)
+    GetAccountIndexes,
+    /// Loads every account persisted in storage into memory.
+    /// Expected response: [`Accounts`](crate::message_interface::Response::Accounts)
+    LoadAllAccounts,
+    /// Fetches every participation event the given account's connected node knows about.
+    /// Expected response: [`ParticipationEvents`](crate::message_interface::Response::ParticipationEvents)
+    #[cfg(feature = "participation")]
+    GetParticipationEvents {
+        /// Index of the account whose node to query.
+        account_index: u32,
+    },
+    /// Fetches the current phase of a single participation event.
+    /// Expected response:
+    /// [`ParticipationEventStatus`](crate::message_interface::Response::ParticipationEventStatus)
+    #[cfg(feature = "participation")]
+    GetParticipationEventStatus {
+        /// Index of the account whose node to query.
+        account_index: u32,
+        /// Event to fetch the status of.
+        event_id: ParticipationEventId,
+    },
+    /// Computes the given account's participation overview.
+    /// Expected response:
+    /// [`AccountParticipationOverview`](crate::message_interface::Response::AccountParticipationOverview)
+    #[cfg(feature = "participation")]
+    GetParticipationOverview {
+        /// Index of the account to compute the overview for.
+        account_index: u32,
+    },
+}
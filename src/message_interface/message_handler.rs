@@ -0,0 +1,68 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    account_manager::AccountManager,
+    message_interface::{message::Message, response::Response},
+};
+
+/// Dispatches incoming [`Message`]s to the [`AccountManager`] and converts the result into a [`Response`].
+pub struct MessageHandler {
+    account_manager: AccountManager,
+}
+
+impl MessageHandler {
+    /// Creates a new handler around an existing [`AccountManager`].
+    pub fn new(account_manager: AccountManager) -> Self {
+        Self { account_manager }
+    }
+
+    /// Handles a single [`Message`], returning the [`Response`] it produces.
+    pub async fn handle(&self, message: Message) -> Response {
+        match message {
+            #[cfg(feature = "ledger_nano")]
+            Message::GetLedgerNanoStatus => match self.account_manager.get_ledger_nano_status().await {
+                Ok(status) => Response::LedgerNanoStatus(status),
+                Err(e) => Response::Error(e),
+            },
+            Message::GetAccountIndexes => match self.account_manager.get_account_indexes().await {
+                Ok(indexes) => Response::AccountIndexes(indexes),
+                Err(e) => Response::Error(e),
+            },
+            Message::LoadAllAccounts => match self.account_manager.load_all_accounts().await {
+                Ok(accounts) => Response::Accounts(accounts),
+                Err(e) => Response::Error(e),
+            },
+            #[cfg(feature = "participation")]
+            Message::GetParticipationEvents { account_index } => {
+                match self.account_manager.get_account(account_index).await {
+                    Ok(account) => match account.get_participation_events().await {
+                        Ok(events) => Response::ParticipationEvents(events),
+                        Err(e) => Response::Error(e),
+                    },
+                    Err(e) => Response::Error(e),
+                }
+            }
+            #[cfg(feature = "participation")]
+            Message::GetParticipationEventStatus { account_index, event_id } => {
+                match self.account_manager.get_account(account_index).await {
+                    Ok(account) => match account.get_participation_event_status(&event_id).await {
+                        Ok(status) => Response::ParticipationEventStatus(status),
+                        Err(e) => Response::Error(e),
+                    },
+                    Err(e) => Response::Error(e),
+                }
+            }
+            #[cfg(feature = "participation")]
+            Message::GetParticipationOverview { account_index } => {
+                match self.account_manager.get_account(account_index).await {
+                    Ok(account) => match account.get_participation_overview().await {
+                        Ok(overview) => Response::AccountParticipationOverview(overview),
+                        Err(e) => Response::Error(e),
+                    },
+                    Err(e) => Response::Error(e),
+                }
+            }
+        }
+    }
+}
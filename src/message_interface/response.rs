@@ -8,8 +8,14 @@ use iota_client::{
     bee_block::output::OutputId,
     NodeInfoWrapper,
 };
+#[cfg(feature = "participation")]
+use iota_client::participation::types::{EventStatus, ParticipationEventWithNodes};
 use serde::Serialize;
 
+#[cfg(feature = "participation")]
+use crate::account::types::participation::ParticipationOverview;
+#[cfg(feature = "ledger_nano")]
+use crate::message_interface::dtos::LedgerNanoStatusDto;
 use crate::{
     account::{operations::transfer::TransferResult, types::address::AccountAddress},
     message_interface::dtos::{
@@ -19,6 +25,11 @@ use crate::{
 };
 
 /// The response message.
+///
+/// Serialized to a tagged `{ "type": ..., "payload": ... }` JSON object, this is shared by the native actor
+/// loop and, on `wasm32-unknown-unknown`, by `send_message`'s `String` return value. Any failure caught on
+/// the wasm side is carried back through the [`Error`](Response::Error) and [`Panic`](Response::Panic)
+/// variants rather than a trap, since there's no actor channel to propagate it through.
 #[derive(Serialize)]
 #[serde(tag = "type", content = "payload")]
 pub enum Response {
@@ -26,8 +37,12 @@ pub enum Response {
     /// [`CreateAccount`](crate::message_interface::Message::CreateAccount),
     /// [`GetAccount`](crate::message_interface::Message::GetAccount)
     Account(AccountDto),
-    /// Response for [`GetAccounts`](crate::message_interface::Message::GetAccounts)
+    /// Response for
+    /// [`GetAccounts`](crate::message_interface::Message::GetAccounts),
+    /// [`LoadAllAccounts`](crate::message_interface::Message::LoadAllAccounts)
     Accounts(Vec<AccountDto>),
+    /// Response for [`GetAccountIndexes`](crate::message_interface::Message::GetAccountIndexes)
+    AccountIndexes(Vec<u32>),
     /// Response for [`ListAddresses`](crate::message_interface::AccountMethod::ListAddresses)
     Addresses(Vec<AccountAddress>),
     /// Response for
@@ -54,6 +69,8 @@ pub enum Response {
     /// Response for
     /// [`ListTransactions`](crate::message_interface::AccountMethod::ListTransactions),
     /// [`ListPendingTransactions`](crate::message_interface::AccountMethod::ListPendingTransactions)
+    /// Each [`TransactionDto`] carries a `recipients` field classifying its outputs as external or
+    /// wallet-internal, so consumers can filter change outputs out of balance/history displays.
     Transactions(Vec<TransactionDto>),
     /// Response for
     /// [`SignTransaction`](crate::message_interface::AccountMethod::SignTransaction)
@@ -74,13 +91,27 @@ pub enum Response {
     /// [`SendNft`](crate::message_interface::AccountMethod::SendNft),
     /// [`SendTransfer`](crate::message_interface::AccountMethod::SendTransfer)
     /// [`SubmitAndStoreTransaction`](crate::message_interface::AccountMethod::SubmitAndStoreTransaction)
+    /// The contained transaction's outputs are classified as external or wallet-internal recipients.
     SentTransfer(TransferResult),
     /// Response for [`TryCollectOutputs`](crate::message_interface::AccountMethod::TryCollectOutputs),
     /// [`CollectOutputs`](crate::message_interface::AccountMethod::CollectOutputs)
     SentTransfers(Vec<TransferResult>),
     /// Response for
     /// [`IsStrongholdPasswordAvailable`](crate::message_interface::Message::IsStrongholdPasswordAvailable)
+    #[cfg(feature = "stronghold")]
     StrongholdPasswordIsAvailable(bool),
+    /// Response for
+    /// [`GetParticipationEvents`](crate::message_interface::Message::GetParticipationEvents)
+    #[cfg(feature = "participation")]
+    ParticipationEvents(Vec<ParticipationEventWithNodes>),
+    /// Response for
+    /// [`GetParticipationEventStatus`](crate::message_interface::Message::GetParticipationEventStatus)
+    #[cfg(feature = "participation")]
+    ParticipationEventStatus(EventStatus),
+    /// Response for
+    /// [`GetParticipationOverview`](crate::message_interface::Message::GetParticipationOverview)
+    #[cfg(feature = "participation")]
+    AccountParticipationOverview(ParticipationOverview),
     /// An error occurred.
     Error(Error),
     /// A panic occurred.
@@ -89,6 +120,9 @@ pub enum Response {
     GeneratedMnemonic(String),
     /// Response for [`GetNodeInfo`](crate::message_interface::Message::GetNodeInfo)
     NodeInfo(NodeInfoWrapper),
+    /// Response for [`GetLedgerNanoStatus`](crate::message_interface::Message::GetLedgerNanoStatus)
+    #[cfg(feature = "ledger_nano")]
+    LedgerNanoStatus(LedgerNanoStatusDto),
     /// Response for
     /// [`Backup`](crate::message_interface::Message::Backup),
     /// [`ClearStrongholdPassword`](crate::message_interface::Message::ClearStrongholdPassword),
@@ -112,6 +146,7 @@ impl Debug for Response {
         match self {
             Response::Account(account) => write!(f, "Account({:?})", account),
             Response::Accounts(accounts) => write!(f, "Accounts({:?})", accounts),
+            Response::AccountIndexes(account_indexes) => write!(f, "AccountIndexes({:?})", account_indexes),
             Response::Addresses(addresses) => write!(f, "Addresses({:?})", addresses),
             Response::AddressesWithUnspentOutputs(addresses) => {
                 write!(f, "AddressesWithUnspentOutputs({:?})", addresses)
@@ -130,13 +165,24 @@ impl Debug for Response {
             Response::Balance(balance) => write!(f, "Balance({:?})", balance),
             Response::SentTransfer(transfer) => write!(f, "SentTransfer({:?})", transfer),
             Response::SentTransfers(transfers) => write!(f, "SentTransfers({:?})", transfers),
+            #[cfg(feature = "stronghold")]
             Response::StrongholdPasswordIsAvailable(is_available) => {
                 write!(f, "StrongholdPasswordIsAvailable({:?})", is_available)
             }
+            #[cfg(feature = "participation")]
+            Response::ParticipationEvents(events) => write!(f, "ParticipationEvents({:?})", events),
+            #[cfg(feature = "participation")]
+            Response::ParticipationEventStatus(status) => write!(f, "ParticipationEventStatus({:?})", status),
+            #[cfg(feature = "participation")]
+            Response::AccountParticipationOverview(overview) => {
+                write!(f, "AccountParticipationOverview({:?})", overview)
+            }
             Response::Error(error) => write!(f, "Error({:?})", error),
             Response::Panic(panic_msg) => write!(f, "Panic({:?})", panic_msg),
             Response::GeneratedMnemonic(_) => write!(f, "GeneratedMnemonic(<omitted>)"),
             Response::NodeInfo(info) => write!(f, "NodeInfo({:?})", info),
+            #[cfg(feature = "ledger_nano")]
+            Response::LedgerNanoStatus(status) => write!(f, "LedgerNanoStatus({:?})", status),
             Response::Ok(()) => write!(f, "Ok(())"),
         }
     }
@@ -0,0 +1,63 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
+
+use crate::{storage::StorageAdapter, Error};
+
+/// A [`StorageAdapter`] backed by the browser's IndexedDB, used instead of the native on-disk database when
+/// compiled to `wasm32-unknown-unknown`.
+pub struct IndexedDbStorageAdapter {
+    database_name: String,
+}
+
+impl IndexedDbStorageAdapter {
+    /// Creates an adapter for the named IndexedDB database used to store account state. Nothing is opened
+    /// here; each `get`/`set`/`delete` passes the name to the host's JS helpers.
+    pub async fn new(database_name: impl Into<String>) -> crate::Result<Self> {
+        Ok(Self {
+            database_name: database_name.into(),
+        })
+    }
+}
+
+#[async_trait(?Send)]
+impl StorageAdapter for IndexedDbStorageAdapter {
+    async fn get(&self, key: &str) -> crate::Result<Option<String>> {
+        indexeddb_get(&self.database_name, key)
+            .await
+            .map(|value| value.as_string())
+            .map_err(|err| Error::Error(js_error_to_string(err)))
+    }
+
+    async fn set(&self, key: &str, record: String) -> crate::Result<()> {
+        indexeddb_set(&self.database_name, key, record)
+            .await
+            .map_err(|err| Error::Error(js_error_to_string(err)))
+    }
+
+    async fn delete(&self, key: &str) -> crate::Result<()> {
+        indexeddb_delete(&self.database_name, key)
+            .await
+            .map_err(|err| Error::Error(js_error_to_string(err)))
+    }
+}
+
+fn js_error_to_string(err: JsValue) -> String {
+    err.as_string().unwrap_or_else(|| format!("{:?}", err))
+}
+
+// Real bindings to the `idb-keyval`-style JS helpers the wasm host is expected to inject as
+// `globalThis.__iotaSdkIndexedDb`; the actual IndexedDB transaction handling lives there rather than here.
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = __iotaSdkIndexedDb, js_name = get, catch)]
+    async fn indexeddb_get(database_name: &str, key: &str) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_namespace = __iotaSdkIndexedDb, js_name = set, catch)]
+    async fn indexeddb_set(database_name: &str, key: &str, record: String) -> Result<(), JsValue>;
+
+    #[wasm_bindgen(js_namespace = __iotaSdkIndexedDb, js_name = delete, catch)]
+    async fn indexeddb_delete(database_name: &str, key: &str) -> Result<(), JsValue>;
+}
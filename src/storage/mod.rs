@@ -0,0 +1,19 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#[cfg(target_arch = "wasm32")]
+pub mod indexeddb;
+
+use async_trait::async_trait;
+
+/// A key/value persistence backend for account state. Implemented per platform: native builds use a local
+/// database, `wasm32-unknown-unknown` builds use [`indexeddb::IndexedDbStorageAdapter`].
+#[async_trait(?Send)]
+pub trait StorageAdapter {
+    /// Fetches the raw value stored under `key`, if any.
+    async fn get(&self, key: &str) -> crate::Result<Option<String>>;
+    /// Stores `record` under `key`, overwriting any previous value.
+    async fn set(&self, key: &str, record: String) -> crate::Result<()>;
+    /// Removes the value stored under `key`, if any.
+    async fn delete(&self, key: &str) -> crate::Result<()>;
+}
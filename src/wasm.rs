@@ -0,0 +1,64 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! The `wasm32-unknown-unknown` entry point. There's no actor loop to hand a [`Response`] back through on
+//! this target, so [`AccountManagerMethodHandler::send_message`] takes and returns plain JSON strings
+//! instead: any [`Message`] that would normally go over the native channel is deserialized here, dispatched
+//! through the same [`MessageHandler`] the native build uses, and the resulting [`Response`] (including the
+//! [`Error`](Response::Error) and [`Panic`](Response::Panic) variants) is serialized back out rather than
+//! thrown as a JS exception.
+//!
+//! Not yet a working wasm32 target: [`AccountManagerMethodHandler::new`] depends on
+//! `AccountManagerOptions::build()`, which isn't implemented anywhere in this series, and there's no
+//! Cargo.toml in this tree to carry the wasm32-only dependency/feature wiring (`wasm-bindgen`, `js-sys`,
+//! `wasm-bindgen-futures`, a `getrandom/js` feature, `tokio`'s non-`rt`/`net` subset, etc.) that a
+//! `wasm32-unknown-unknown` build would need. Both have to land before this compiles, let alone links.
+
+use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
+
+use crate::{
+    account_manager::AccountManagerOptions,
+    message_interface::{message::Message, message_handler::MessageHandler, response::Response},
+};
+
+/// Thin `wasm_bindgen` wrapper around [`MessageHandler`] exposing a single `String -> String` entry point,
+/// since `wasm_bindgen` can't export the handler's native async API directly.
+#[wasm_bindgen]
+pub struct AccountManagerMethodHandler {
+    handler: MessageHandler,
+}
+
+#[wasm_bindgen]
+impl AccountManagerMethodHandler {
+    /// Builds the [`AccountManager`](crate::account_manager::AccountManager) described by `options` - the
+    /// same JSON configuration the native entry point accepts - and wraps it in a handler JS can call
+    /// [`send_message`](Self::send_message) on. `AccountManagerMethodHandler` has no public fields and no
+    /// other way to construct one, so this is the only path JS has to obtain an instance.
+    ///
+    /// Not buildable yet on `wasm32-unknown-unknown` (see the module-level note): `AccountManagerOptions`
+    /// is assumed to exist with a `build()` that constructs an [`AccountManager`](crate::account_manager::AccountManager)
+    /// from this same JSON shape, matching the native side, but landing it and the manifest wiring is
+    /// outside this series.
+    pub async fn new(options: String) -> Result<AccountManagerMethodHandler, JsValue> {
+        let options: AccountManagerOptions =
+            serde_json::from_str(&options).map_err(|e| JsValue::from_str(&e.to_string()))?;
+        let account_manager = options.build().await.map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        Ok(Self {
+            handler: MessageHandler::new(account_manager),
+        })
+    }
+
+    /// Deserializes `message` as a [`Message`], dispatches it through the wrapped [`MessageHandler`], and
+    /// returns the resulting [`Response`] serialized back to JSON.
+    pub async fn send_message(&self, message: String) -> String {
+        let response = match serde_json::from_str::<Message>(&message) {
+            Ok(message) => self.handler.handle(message).await,
+            Err(e) => Response::Error(crate::Error::Error(e.to_string())),
+        };
+
+        serde_json::to_string(&response).unwrap_or_else(|e| {
+            serde_json::to_string(&Response::Panic(e.to_string())).expect("Response::Panic always serializes")
+        })
+    }
+}